@@ -0,0 +1,93 @@
+use crate::{SegtreeAccessError, SegtreeRangeError};
+
+/// Dual segment tree: apply a commutative-monoid action to a whole range in
+/// O(log n), then read a single index's accumulated value in O(log n).
+///
+/// Actions are stored lazily at the canonical cover nodes and never pushed
+/// down; [`get`](Self::get) folds every action on the root-to-leaf path. This
+/// needs only the composition `compose` and its identity `id`, with no merge
+/// over children, making it lighter than a full [`LazySegtree`](crate::LazySegtree).
+#[derive(Debug, Clone)]
+pub struct DualSegtree<F: Clone> {
+    tree: Vec<F>,
+    data_len: usize,
+    compose: fn(&F, &F) -> F,
+    id: F,
+}
+
+impl<F: Clone> DualSegtree<F> {
+    /// Constructs a dual segtree over `len` indices, each starting at `id`
+    pub fn new(len: usize, compose: fn(&F, &F) -> F, id: F) -> DualSegtree<F> {
+        DualSegtree {
+            tree: vec![id.clone(); 2 * len],
+            data_len: len,
+            compose,
+            id,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data_len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data_len == 0
+    }
+
+    /// Composes `delta` into every index of the inclusive range `l..=r`
+    pub fn apply_range(&mut self, l: usize, r: usize, delta: F) {
+        let mut l = l + self.data_len;
+        let mut r = r + self.data_len + 1;
+
+        while l < r {
+            if l & 1 == 1 {
+                self.tree[l] = (self.compose)(&self.tree[l], &delta);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                self.tree[r] = (self.compose)(&self.tree[r], &delta);
+            }
+
+            l >>= 1;
+            r >>= 1;
+        }
+    }
+
+    pub fn try_apply_range(
+        &mut self,
+        l: usize,
+        r: usize,
+        delta: F,
+    ) -> Result<(), SegtreeRangeError> {
+        if l > r {
+            Err(SegtreeRangeError::InvalidRange)
+        } else if r >= self.data_len {
+            Err(SegtreeRangeError::RangeOutOfBounds)
+        } else {
+            self.apply_range(l, r, delta);
+            Ok(())
+        }
+    }
+
+    /// Folds every action on the root-to-leaf path of `index`
+    pub fn get(&self, index: usize) -> F {
+        let mut crr = index + self.data_len;
+        let mut res = self.id.clone();
+
+        while crr != 0 {
+            res = (self.compose)(&res, &self.tree[crr]);
+            crr >>= 1;
+        }
+
+        res
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<F, SegtreeAccessError> {
+        if index >= self.data_len {
+            Err(SegtreeAccessError::IndexOutOfBounds)
+        } else {
+            Ok(self.get(index))
+        }
+    }
+}
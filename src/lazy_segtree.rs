@@ -0,0 +1,177 @@
+use crate::SegtreeRangeError;
+
+/// Segment tree with lazy propagation, supporting applying a mapping `F` to a
+/// whole range and answering range queries, both in O(log n).
+///
+/// Besides the `merge_fn`/`neutral_elem` monoid over `T` carried by
+/// [`StaticSegtree`](crate::StaticSegtree), a `LazySegtree` stores a tree of
+/// pending actions `F`: a composition `compose` (apply-then-older) with
+/// identity `id`, and an `apply` that maps a node's aggregate given the length
+/// of the segment it covers (the length is needed for e.g. range-add /
+/// range-sum).
+#[derive(Debug, Clone)]
+pub struct LazySegtree<T: Clone, F: Clone> {
+    tree: Vec<T>,
+    lazy: Vec<F>,
+    data_len: usize,
+    merge_fn: fn(&T, &T) -> T,
+    neutral_elem: T,
+    compose: fn(&F, &F) -> F,
+    id: F,
+    apply: fn(&F, &T, usize) -> T,
+}
+
+impl<T: Clone, F: Clone> LazySegtree<T, F> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_slice(
+        original: &[T],
+        merge_fn: fn(&T, &T) -> T,
+        neutral_elem: T,
+        compose: fn(&F, &F) -> F,
+        id: F,
+        apply: fn(&F, &T, usize) -> T,
+    ) -> LazySegtree<T, F> {
+        let len = original.len();
+        let mut segtree = LazySegtree {
+            tree: vec![neutral_elem.clone(); 4 * len.max(1)],
+            lazy: vec![id.clone(); 4 * len.max(1)],
+            data_len: len,
+            merge_fn,
+            neutral_elem,
+            compose,
+            id,
+            apply,
+        };
+
+        if len != 0 {
+            segtree.build(1, 0, len, original);
+        }
+
+        segtree
+    }
+
+    /// Recursively fills node `node`, which covers `[lo, hi)`, from `src`
+    fn build(&mut self, node: usize, lo: usize, hi: usize, src: &[T]) {
+        if hi - lo == 1 {
+            self.tree[node] = src[lo].clone();
+            return;
+        }
+
+        let mid = (lo + hi) / 2;
+        self.build(node << 1, lo, mid, src);
+        self.build(node << 1 | 1, mid, hi, src);
+        self.tree[node] =
+            (self.merge_fn)(&self.tree[node << 1], &self.tree[node << 1 | 1]);
+    }
+
+    pub fn len(&self) -> usize {
+        self.data_len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data_len == 0
+    }
+
+    /// Applies `f` to node `node`, which covers `[lo, hi)`: maps its aggregate
+    /// and composes `f` into its pending lazy action
+    fn apply_node(&mut self, node: usize, lo: usize, hi: usize, f: &F) {
+        self.tree[node] = (self.apply)(f, &self.tree[node], hi - lo);
+        self.lazy[node] = (self.compose)(f, &self.lazy[node]);
+    }
+
+    /// Pushes node `node`'s pending action into its two children and clears it
+    fn push_down(&mut self, node: usize, lo: usize, hi: usize) {
+        let mid = (lo + hi) / 2;
+        let f = self.lazy[node].clone();
+        self.apply_node(node << 1, lo, mid, &f);
+        self.apply_node(node << 1 | 1, mid, hi, &f);
+        self.lazy[node] = self.id.clone();
+    }
+
+    fn apply_range_internal(
+        &mut self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        ql: usize,
+        qr: usize,
+        f: &F,
+    ) {
+        if qr <= lo || hi <= ql {
+            return;
+        }
+        if ql <= lo && hi <= qr {
+            self.apply_node(node, lo, hi, f);
+            return;
+        }
+
+        self.push_down(node, lo, hi);
+        let mid = (lo + hi) / 2;
+        self.apply_range_internal(node << 1, lo, mid, ql, qr, f);
+        self.apply_range_internal(node << 1 | 1, mid, hi, ql, qr, f);
+        self.tree[node] =
+            (self.merge_fn)(&self.tree[node << 1], &self.tree[node << 1 | 1]);
+    }
+
+    fn query_internal(
+        &mut self,
+        node: usize,
+        lo: usize,
+        hi: usize,
+        ql: usize,
+        qr: usize,
+    ) -> T {
+        if qr <= lo || hi <= ql {
+            return self.neutral_elem.clone();
+        }
+        if ql <= lo && hi <= qr {
+            return self.tree[node].clone();
+        }
+
+        self.push_down(node, lo, hi);
+        let mid = (lo + hi) / 2;
+        let resl = self.query_internal(node << 1, lo, mid, ql, qr);
+        let resr = self.query_internal(node << 1 | 1, mid, hi, ql, qr);
+        (self.merge_fn)(&resl, &resr)
+    }
+
+    /// Applies the action `f` to every index in the inclusive range `l..=r`
+    pub fn apply_range(&mut self, l: usize, r: usize, f: F) {
+        self.apply_range_internal(1, 0, self.data_len, l, r + 1, &f);
+    }
+
+    pub fn try_apply_range(
+        &mut self,
+        l: usize,
+        r: usize,
+        f: F,
+    ) -> Result<(), SegtreeRangeError> {
+        if l > r {
+            Err(SegtreeRangeError::InvalidRange)
+        } else if r >= self.data_len {
+            Err(SegtreeRangeError::RangeOutOfBounds)
+        } else {
+            self.apply_range(l, r, f);
+            Ok(())
+        }
+    }
+
+    /// Queries the aggregate over the inclusive range `l..=r`
+    pub fn query(&mut self, l: usize, r: usize) -> T {
+        self.query_internal(1, 0, self.data_len, l, r + 1)
+    }
+
+    pub fn try_query(
+        &mut self,
+        l: usize,
+        r: usize,
+    ) -> Result<T, SegtreeRangeError> {
+        if l > r {
+            Err(SegtreeRangeError::InvalidRange)
+        } else if r >= self.data_len {
+            Err(SegtreeRangeError::RangeOutOfBounds)
+        } else {
+            Ok(self.query(l, r))
+        }
+    }
+}
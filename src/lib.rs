@@ -1,5 +1,13 @@
 use std::ops::Add;
 
+mod dual_segtree;
+mod lazy_segtree;
+mod monoid;
+
+pub use dual_segtree::DualSegtree;
+pub use lazy_segtree::LazySegtree;
+pub use monoid::{Additive, Concat, Max, Min, Monoid, Sum};
+
 #[derive(Debug)]
 pub struct StaticSegtree<T: Clone> {
     tree: Vec<T>,
@@ -155,4 +163,177 @@ impl<T: Clone> StaticSegtree<T> {
             Ok(self.query(l, r))
         }
     }
+
+    /// Returns the largest `r` such that `pred` holds on the aggregate over
+    /// the half-open range `[l, r)`, assuming `pred(&neutral_elem)` is true
+    /// (so the empty prefix `l..l` always passes). Note this is the half-open
+    /// convention, unlike the inclusive [`query`](Self::query); the empty range
+    /// yields `neutral_elem`, any non-empty `l..r` equals `self.query(l, r - 1)`.
+    ///
+    /// Runs in O(log n) by walking the implicit tree rather than binary
+    /// searching with repeated queries. Every node of the canonical cover of
+    /// `[l, data_len)`, and every node below it, spans a contiguous index range
+    /// of the `2*data_len` layout (other internal nodes need not when
+    /// `data_len` is not a power of two), so we fold over that cover in
+    /// left-to-right order and, at the first node
+    /// that makes `pred` fail, descend into it keeping the left child while the
+    /// prefix still satisfies `pred` to pin the boundary leaf.
+    pub fn max_right<P: Fn(&T) -> bool>(&self, l: usize, pred: P) -> usize {
+        if l >= self.data_len {
+            return self.data_len;
+        }
+
+        let mut sm = self.neutral_elem.clone();
+        for mut node in self.cover(l, self.data_len) {
+            let cand = (self.merge_fn)(&sm, &self.tree[node]);
+            if pred(&cand) {
+                sm = cand;
+                continue;
+            }
+            while node < self.data_len {
+                node <<= 1;
+                let next = (self.merge_fn)(&sm, &self.tree[node]);
+                if pred(&next) {
+                    sm = next;
+                    node += 1;
+                }
+            }
+            return node - self.data_len;
+        }
+
+        self.data_len
+    }
+
+    /// Returns the smallest `l` such that `pred` holds on the aggregate over
+    /// the half-open range `[l, r)`, assuming `pred(&neutral_elem)` is true
+    /// (so the empty range `r..r` always passes). The mirror image of
+    /// [`max_right`](Self::max_right), walking the tree in O(log n); see it for
+    /// the half-open convention and the contiguous-subtree reasoning.
+    pub fn min_left<P: Fn(&T) -> bool>(&self, r: usize, pred: P) -> usize {
+        if r == 0 {
+            return 0;
+        }
+
+        let mut sm = self.neutral_elem.clone();
+        for &node_ro in self.cover(0, r).iter().rev() {
+            let mut node = node_ro;
+            let cand = (self.merge_fn)(&self.tree[node], &sm);
+            if pred(&cand) {
+                sm = cand;
+                continue;
+            }
+            while node < self.data_len {
+                node = node << 1 | 1;
+                let next = (self.merge_fn)(&self.tree[node], &sm);
+                if pred(&next) {
+                    sm = next;
+                    node -= 1;
+                }
+            }
+            return node + 1 - self.data_len;
+        }
+
+        0
+    }
+
+    /// The canonical cover of the half-open range `[l, r)`: the minimal set of
+    /// subtree roots whose leaves partition it, in left-to-right order.
+    fn cover(&self, l: usize, r: usize) -> Vec<usize> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        let mut lo = l + self.data_len;
+        let mut hi = r + self.data_len;
+
+        while lo < hi {
+            if lo & 1 == 1 {
+                left.push(lo);
+                lo += 1;
+            }
+            if hi & 1 == 1 {
+                hi -= 1;
+                right.push(hi);
+            }
+            lo >>= 1;
+            hi >>= 1;
+        }
+
+        left.extend(right.into_iter().rev());
+        left
+    }
+}
+
+impl<T: Clone + PartialEq> StaticSegtree<T> {
+    /// Builds a segtree whose merge and neutral element are taken from the
+    /// [`Monoid`] `M`, so callers need not re-pass a closure and identity:
+    /// `StaticSegtree::from_slice_with::<Max<i64>>(&a)`
+    pub fn from_slice_with<M: Monoid<Item = T>>(
+        original: &[T],
+    ) -> StaticSegtree<T> {
+        StaticSegtree::from_slice(original, M::op, M::identity())
+    }
+}
+
+impl<T: Clone> StaticSegtree<T> {
+    /// Iterates over the leaves (the original data) in index order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.tree[self.data_len..2 * self.data_len].iter()
+    }
+
+    /// Compresses the distinct coordinate `keys` to `0..k` and returns a
+    /// segtree over that index space (leaves starting at `neutral_elem`)
+    /// together with a lookup from an original key to its tree index. Useful
+    /// for the offline idiom of progressively populating a segtree keyed by a
+    /// sparse, sorted value domain, and composes with
+    /// [`max_right`](Self::max_right) / [`min_left`](Self::min_left).
+    pub fn from_keys<K: Ord + Clone>(
+        keys: &[K],
+        merge_fn: fn(&T, &T) -> T,
+        neutral_elem: T,
+    ) -> (StaticSegtree<T>, impl Fn(&K) -> usize) {
+        let mut sorted: Vec<K> = keys.to_vec();
+        sorted.sort();
+        sorted.dedup();
+
+        let data = vec![neutral_elem.clone(); sorted.len()];
+        let segtree = StaticSegtree::from_slice(&data, merge_fn, neutral_elem);
+
+        let lookup = move |key: &K| {
+            sorted
+                .binary_search(key)
+                .expect("key not present in the compressed coordinate domain")
+        };
+
+        (segtree, lookup)
+    }
+}
+
+impl<T> FromIterator<T> for StaticSegtree<T>
+where
+    T: Add<T, Output = T> + Clone + Default,
+{
+    /// Collects the iterator and builds a summing segtree, matching the merge
+    /// and neutral element of the [`Default`] construction
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> StaticSegtree<T> {
+        let data: Vec<T> = iter.into_iter().collect();
+        StaticSegtree::from_slice(&data, |a, b| a.clone() + b.clone(), T::default())
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a StaticSegtree<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> std::slice::Iter<'a, T> {
+        self.tree[self.data_len..2 * self.data_len].iter()
+    }
+}
+
+impl<T: Clone> IntoIterator for StaticSegtree<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Yields the original data back, consuming the segtree
+    fn into_iter(mut self) -> std::vec::IntoIter<T> {
+        self.tree.split_off(self.data_len).into_iter()
+    }
 }
@@ -0,0 +1,86 @@
+use std::marker::PhantomData;
+use std::ops::Add;
+
+/// An algebraic monoid: an associative binary operation with an identity.
+///
+/// Implementing `Monoid` lets a [`StaticSegtree`](crate::StaticSegtree) be
+/// built without re-passing a closure and neutral element at every call site,
+/// via [`StaticSegtree::from_slice_with`](crate::StaticSegtree::from_slice_with).
+pub trait Monoid {
+    type Item: Clone + PartialEq;
+
+    /// The identity element, i.e. `op(&identity(), x) == x` for every `x`
+    fn identity() -> Self::Item;
+
+    /// The associative binary operation
+    fn op(a: &Self::Item, b: &Self::Item) -> Self::Item;
+}
+
+/// Monoid picking the larger of two values
+pub struct Max<T>(PhantomData<T>);
+
+/// Monoid picking the smaller of two values
+pub struct Min<T>(PhantomData<T>);
+
+/// Monoid summing two values
+pub struct Sum<T>(PhantomData<T>);
+
+macro_rules! impl_ord_monoids {
+    ($($t:ty),* $(,)?) => {$(
+        impl Monoid for Max<$t> {
+            type Item = $t;
+            fn identity() -> $t { <$t>::MIN }
+            fn op(a: &$t, b: &$t) -> $t { (*a).max(*b) }
+        }
+
+        impl Monoid for Min<$t> {
+            type Item = $t;
+            fn identity() -> $t { <$t>::MAX }
+            fn op(a: &$t, b: &$t) -> $t { (*a).min(*b) }
+        }
+
+        impl Monoid for Sum<$t> {
+            type Item = $t;
+            fn identity() -> $t { 0 }
+            fn op(a: &$t, b: &$t) -> $t { a + b }
+        }
+    )*};
+}
+
+impl_ord_monoids!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Monoid concatenating two sequences
+pub struct Concat<T>(PhantomData<T>);
+
+impl<T: Clone + PartialEq> Monoid for Concat<T> {
+    type Item = Vec<T>;
+
+    fn identity() -> Vec<T> {
+        Vec::new()
+    }
+
+    fn op(a: &Vec<T>, b: &Vec<T>) -> Vec<T> {
+        let mut res = a.clone();
+        res.extend_from_slice(b);
+        res
+    }
+}
+
+/// Adapter turning any `T: Add + Default` into its summing monoid, mirroring
+/// the [`Default`](crate::StaticSegtree) construction of `StaticSegtree`
+pub struct Additive<T>(PhantomData<T>);
+
+impl<T> Monoid for Additive<T>
+where
+    T: Add<T, Output = T> + Clone + Default + PartialEq,
+{
+    type Item = T;
+
+    fn identity() -> T {
+        T::default()
+    }
+
+    fn op(a: &T, b: &T) -> T {
+        a.clone() + b.clone()
+    }
+}
@@ -3,7 +3,9 @@ mod tests {
     use std::ops::Range;
 
     use rand::{rngs::StdRng, Rng, SeedableRng};
-    use segtree_rs::StaticSegtree;
+    use segtree_rs::{
+        Additive, Concat, DualSegtree, LazySegtree, Max, Min, StaticSegtree, Sum,
+    };
 
     #[test]
     fn static_segtree() {
@@ -25,7 +27,7 @@ mod tests {
             inner
         });
 
-        let mut segtree = StaticSegtree::from_vec(&data, MERGE_FN, Vec::new());
+        let mut segtree = StaticSegtree::from_slice(&data, MERGE_FN, Vec::new());
 
         dbg!(&data);
         dbg!(&segtree);
@@ -59,4 +61,181 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn max_right_min_left() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        const MERGE_FN: fn(&i64, &i64) -> i64 = |a, b| a + b;
+
+        // cover non-power-of-two sizes, where some internal nodes of the
+        // layout do not span a contiguous index range
+        for n in 1..=33usize {
+            let data: Vec<i64> =
+                (0..n).map(|_| rng.gen_range(0..16)).collect();
+            let segtree = StaticSegtree::from_slice(&data, MERGE_FN, 0);
+
+            for bound in -1..=(16 * n as i64) {
+                let pred = |x: &i64| *x <= bound;
+
+                // max_right(l): largest r with sum(data[l..r]) <= bound
+                for l in 0..=n {
+                    let mut r = l;
+                    let mut acc = 0i64;
+                    while r < n && acc + data[r] <= bound {
+                        acc += data[r];
+                        r += 1;
+                    }
+                    assert_eq!(segtree.max_right(l, pred), r, "max_right n={n} l={l} bound={bound}");
+                }
+
+                // min_left(r): smallest l with sum(data[l..r]) <= bound
+                for r in 0..=n {
+                    let mut l = r;
+                    let mut acc = 0i64;
+                    while l > 0 && acc + data[l - 1] <= bound {
+                        acc += data[l - 1];
+                        l -= 1;
+                    }
+                    assert_eq!(segtree.min_left(r, pred), l, "min_left n={n} r={r} bound={bound}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn static_segtree_iter() {
+        const MERGE_FN: fn(&i32, &i32) -> i32 = |a, b| a + b;
+
+        for n in 1..=33usize {
+            let data: Vec<i32> = (0..n as i32).collect();
+            let segtree = StaticSegtree::from_slice(&data, MERGE_FN, 0);
+
+            // iter and the borrowing IntoIterator both yield the leaves in order
+            assert_eq!(segtree.iter().copied().collect::<Vec<_>>(), data);
+            assert_eq!((&segtree).into_iter().copied().collect::<Vec<_>>(), data);
+
+            // FromIterator then the consuming IntoIterator round-trips the data
+            let collected: StaticSegtree<i32> = data.iter().copied().collect();
+            assert_eq!(collected.into_iter().collect::<Vec<_>>(), data);
+        }
+    }
+
+    #[test]
+    fn from_slice_with_monoids() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        const N: usize = 256;
+        const VAL_RANGE: Range<i64> = -512..512;
+
+        let data: Vec<i64> =
+            (0..N).map(|_| rng.gen_range(VAL_RANGE)).collect();
+
+        let max_tree = StaticSegtree::from_slice_with::<Max<i64>>(&data);
+        let min_tree = StaticSegtree::from_slice_with::<Min<i64>>(&data);
+        let sum_tree = StaticSegtree::from_slice_with::<Sum<i64>>(&data);
+        let add_tree = StaticSegtree::from_slice_with::<Additive<i64>>(&data);
+
+        for _ in 0..N {
+            let l = rng.gen_range(0..N);
+            let r = rng.gen_range(l..N);
+
+            assert_eq!(max_tree.query(l, r), *data[l..=r].iter().max().unwrap());
+            assert_eq!(min_tree.query(l, r), *data[l..=r].iter().min().unwrap());
+            let brute_sum: i64 = data[l..=r].iter().sum();
+            assert_eq!(sum_tree.query(l, r), brute_sum);
+            assert_eq!(add_tree.query(l, r), brute_sum);
+        }
+
+        // Concat monoid over sequences
+        let seqs: Vec<Vec<i32>> = (0..N).map(|i| vec![i as i32]).collect();
+        let concat_tree = StaticSegtree::from_slice_with::<Concat<i32>>(&seqs);
+        assert_eq!(concat_tree.query(0, N - 1), (0..N as i32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn dual_segtree() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        const N: usize = 100;
+        const VAL_RANGE: Range<i64> = -512..512;
+        const COMPOSE: fn(&i64, &i64) -> i64 = |a, b| a + b;
+
+        let mut data = vec![0i64; N];
+        let mut segtree = DualSegtree::new(N, COMPOSE, 0);
+
+        // mixed range-apply / point-get sequence against the brute array
+        for _ in 0..N {
+            if rng.gen_bool(0.5) {
+                let l = rng.gen_range(0..N);
+                let r = rng.gen_range(l..N);
+                let delta = rng.gen_range(VAL_RANGE);
+                for v in data[l..=r].iter_mut() {
+                    *v += delta;
+                }
+                segtree.apply_range(l, r, delta);
+            } else {
+                let index = rng.gen_range(0..N);
+                assert_eq!(data[index], segtree.get(index));
+            }
+        }
+    }
+
+    #[test]
+    fn from_keys() {
+        const MERGE_FN: fn(&i64, &i64) -> i64 = |a, b| a + b;
+
+        let keys = [40i64, 10, 40, 30, 10];
+        let (mut segtree, index_of) =
+            StaticSegtree::from_keys(&keys, MERGE_FN, 0);
+
+        // distinct keys are compressed to a contiguous, sorted index space
+        assert_eq!(segtree.len(), 3);
+        assert_eq!(index_of(&10), 0);
+        assert_eq!(index_of(&30), 1);
+        assert_eq!(index_of(&40), 2);
+
+        // leaves start at the neutral element and populate by compressed index
+        for i in 0..segtree.len() {
+            assert_eq!(*segtree.get(i), 0);
+        }
+        segtree.set(index_of(&10), 5);
+        segtree.set(index_of(&40), 7);
+        assert_eq!(segtree.query(0, 2), 12);
+        assert_eq!(segtree.query(index_of(&30), index_of(&40)), 7);
+    }
+
+    #[test]
+    fn lazy_segtree() {
+        let mut rng: StdRng = StdRng::seed_from_u64(0);
+        const N: usize = 1024;
+        const VAL_RANGE: Range<i64> = -512..512;
+        // range-add / range-sum monoid
+        const MERGE_FN: fn(&i64, &i64) -> i64 = |a, b| a + b;
+        const COMPOSE: fn(&i64, &i64) -> i64 = |a, b| a + b;
+        const APPLY: fn(&i64, &i64, usize) -> i64 =
+            |f, x, len| x + f * len as i64;
+
+        let mut data: Vec<i64> =
+            (0..N).map(|_| rng.gen_range(VAL_RANGE)).collect();
+
+        let mut segtree =
+            LazySegtree::from_slice(&data, MERGE_FN, 0, COMPOSE, 0, APPLY);
+
+        // mixed range-apply / range-query sequence against the brute array
+        for _ in 0..N {
+            let l = rng.gen_range(0..N);
+            let r = rng.gen_range(l..N);
+
+            if rng.gen_bool(0.5) {
+                let delta = rng.gen_range(VAL_RANGE);
+                for v in data[l..=r].iter_mut() {
+                    *v += delta;
+                }
+                segtree.apply_range(l, r, delta);
+            } else {
+                let data_query: i64 = data[l..=r].iter().sum();
+                let tree_query = segtree.query(l, r);
+
+                assert_eq!(data_query, tree_query);
+            }
+        }
+    }
 }